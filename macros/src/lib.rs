@@ -0,0 +1,210 @@
+//! `#[adapter]`: derives `Adapter::handle` and `Adapter::describe` from a
+//! plain `impl` block of typed async methods, so writing an adapter no
+//! longer means hand-matching on `action` strings and pulling fields out of
+//! a `serde_json::Value` by hand.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, ImplItem, ItemImpl, Pat, Type};
+
+#[proc_macro_attribute]
+pub fn adapter(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemImpl);
+    let self_ty = input.self_ty.clone();
+
+    // By default the adapter name is derived from the type name, but that
+    // only matches the `Registry::register` key by convention; `name =
+    // "..."` lets it be set explicitly so `tools/list` always advertises
+    // the name the adapter is actually registered under.
+    let name_override = if attr.is_empty() {
+        None
+    } else {
+        let name_value = parse_macro_input!(attr as syn::MetaNameValue);
+        if !name_value.path.is_ident("name") {
+            return syn::Error::new_spanned(&name_value.path, "expected `name = \"...\"`")
+                .to_compile_error()
+                .into();
+        }
+        match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => Some(lit_str.value()),
+            _ => {
+                return syn::Error::new_spanned(&name_value.value, "expected a string literal")
+                    .to_compile_error()
+                    .into();
+            }
+        }
+    };
+
+    let mut actions = Vec::new();
+    for item in &input.items {
+        if let ImplItem::Fn(method) = item {
+            actions.push(Action::from_method(&self_ty, method));
+        }
+    }
+
+    let handle_arms = actions.iter().map(Action::handle_arm);
+    let describe_entries = actions.iter().map(Action::describe_entry);
+    let params_structs = actions.iter().map(Action::params_struct);
+    let adapter_name_value = name_override.unwrap_or_else(|| to_snake_case(&type_name(&self_ty)));
+    let adapter_name = syn::LitStr::new(&adapter_name_value, proc_macro2::Span::call_site());
+
+    let expanded = quote! {
+        #input
+
+        #(#params_structs)*
+
+        #[async_trait::async_trait]
+        impl crate::registry::Adapter for #self_ty {
+            async fn handle(
+                &self,
+                action: &str,
+                params: serde_json::Value,
+            ) -> Result<serde_json::Value, crate::error::AdapterError> {
+                match action {
+                    #(#handle_arms)*
+                    _ => Err(crate::error::AdapterError::MethodNotFound),
+                }
+            }
+
+            fn describe(&self) -> crate::schema::AdapterSchema {
+                crate::schema::AdapterSchema {
+                    name: #adapter_name.to_string(),
+                    actions: vec![#(#describe_entries),*],
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+struct Action {
+    name: syn::Ident,
+    params_ty: syn::Ident,
+    fields: Vec<(syn::Ident, Type)>,
+}
+
+impl Action {
+    fn from_method(self_ty: &Type, method: &syn::ImplItemFn) -> Self {
+        let name = method.sig.ident.clone();
+        let params_ty = format_ident!("{}{}Params", type_name(self_ty), to_pascal_case(&name.to_string()));
+
+        let fields = method
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                    Pat::Ident(pat_ident) => Some((pat_ident.ident.clone(), (*pat_type.ty).clone())),
+                    _ => None,
+                },
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+
+        Self { name, params_ty, fields }
+    }
+
+    fn params_struct(&self) -> TokenStream2 {
+        let params_ty = &self.params_ty;
+        let field_names: Vec<_> = self.fields.iter().map(|(name, _)| name).collect();
+        let field_types: Vec<_> = self.fields.iter().map(|(_, ty)| ty).collect();
+
+        quote! {
+            #[derive(serde::Deserialize)]
+            struct #params_ty {
+                #(#field_names: #field_types,)*
+            }
+        }
+    }
+
+    fn handle_arm(&self) -> TokenStream2 {
+        let name = &self.name;
+        let action = syn::LitStr::new(&name.to_string(), name.span());
+        let params_ty = &self.params_ty;
+        let field_names: Vec<_> = self.fields.iter().map(|(name, _)| name).collect();
+
+        quote! {
+            #action => {
+                let params: #params_ty = serde_json::from_value(params)
+                    .map_err(|err| crate::error::AdapterError::invalid_params(err.to_string()))?;
+                let result = self.#name(#(params.#field_names),*).await?;
+                serde_json::to_value(result).map_err(|err| crate::error::AdapterError::Internal(err.into()))
+            }
+        }
+    }
+
+    fn describe_entry(&self) -> TokenStream2 {
+        let action = syn::LitStr::new(&self.name.to_string(), self.name.span());
+        let required: Vec<_> = self.fields.iter().map(|(name, _)| name.to_string()).collect();
+        let property_names: Vec<_> = self.fields.iter().map(|(name, _)| name.to_string()).collect();
+        let property_types: Vec<_> = self.fields.iter().map(|(_, ty)| json_schema_type(ty)).collect();
+
+        quote! {
+            crate::schema::ActionSchema {
+                action: #action.to_string(),
+                params: serde_json::json!({
+                    "type": "object",
+                    "required": [#(#required),*],
+                    "properties": {
+                        #(#property_names: { "type": #property_types }),*
+                    }
+                }),
+            }
+        }
+    }
+}
+
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn json_schema_type(ty: &Type) -> &'static str {
+    match type_name(ty).as_str() {
+        "String" | "str" => "string",
+        "bool" => "boolean",
+        "f32" | "f64" => "number",
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "usize" | "isize" => "integer",
+        "Vec" => "array",
+        _ => "object",
+    }
+}
+
+fn to_pascal_case(input: &str) -> String {
+    input
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_snake_case(input: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in input.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake.trim_end_matches("_adapter").to_string()
+}
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use reqwest::Client;
-use serde_json::Value;
+use serde_json::{json, Value};
+use crate::error::AdapterError;
 use crate::registry::Adapter;
+use crate::schema::{ActionSchema, AdapterSchema};
 
 pub struct ApiAdapter {
     client: Client,
@@ -17,15 +19,33 @@ impl ApiAdapter {
 
 #[async_trait]
 impl Adapter for ApiAdapter {
-    async fn handle(&self, action: &str, params: Value) -> Result<Value, String> {
+    async fn handle(&self, action: &str, params: Value) -> Result<Value, AdapterError> {
         match action {
             "get" => {
-                let url = params["url"].as_str().ok_or("Missing URL")?;
-                let response = self.client.get(url).send().await.map_err(|e| e.to_string())?;
-                let json = response.json::<Value>().await.map_err(|e| e.to_string())?;
+                let url = params["url"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `url`"))?;
+                let response = self.client.get(url).send().await.map_err(anyhow::Error::from)?;
+                let json = response.json::<Value>().await.map_err(anyhow::Error::from)?;
                 Ok(json)
             }
-            _ => Err("Unknown action".to_string()),
+            _ => Err(AdapterError::MethodNotFound),
+        }
+    }
+
+    fn describe(&self) -> AdapterSchema {
+        AdapterSchema {
+            name: "api".to_string(),
+            actions: vec![ActionSchema {
+                action: "get".to_string(),
+                params: json!({
+                    "type": "object",
+                    "required": ["url"],
+                    "properties": {
+                        "url": { "type": "string" }
+                    }
+                }),
+            }],
         }
     }
 }
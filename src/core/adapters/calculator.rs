@@ -1,6 +1,5 @@
-use async_trait::async_trait;
-use serde_json::Value;
-use crate::registry::Adapter;
+use crate::error::AdapterError;
+use mcp_code_macros::adapter;
 
 pub struct CalculatorAdapter;
 
@@ -10,16 +9,9 @@ impl CalculatorAdapter {
     }
 }
 
-#[async_trait]
-impl Adapter for CalculatorAdapter {
-    async fn handle(&self, action: &str, params: Value) -> Result<Value, String> {
-        match action {
-            "add" => {
-                let a = params["a"].as_i64().unwrap_or(0);
-                let b = params["b"].as_i64().unwrap_or(0);
-                Ok(Value::Number((a + b).into()))
-            }
-            _ => Err("Unknown action".to_string()),
-        }
+#[adapter(name = "calc")]
+impl CalculatorAdapter {
+    async fn add(&self, a: i64, b: i64) -> Result<i64, AdapterError> {
+        Ok(a + b)
     }
 }
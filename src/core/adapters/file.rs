@@ -1,7 +1,13 @@
 use async_trait::async_trait;
-use serde_json::Value;
+use serde_json::{json, Value};
+use crate::error::AdapterError;
 use crate::registry::Adapter;
+use crate::schema::{ActionSchema, AdapterSchema};
+use crate::subscription::Subscription;
+use std::time::Duration;
 use tokio::fs;
+use tokio::sync::oneshot;
+use tokio_stream::wrappers::ReceiverStream;
 
 pub struct FileAdapter;
 
@@ -13,15 +19,97 @@ impl FileAdapter {
 
 #[async_trait]
 impl Adapter for FileAdapter {
-    async fn handle(&self, action: &str, params: Value) -> Result<Value, String> {
+    async fn handle(&self, action: &str, params: Value) -> Result<Value, AdapterError> {
         match action {
             "write" => {
-                let path = params["path"].as_str().ok_or("Missing path")?;
-                let content = params["content"].as_str().ok_or("Missing content")?;
-                fs::write(path, content).await.map_err(|e| e.to_string())?;
+                let path = params["path"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `path`"))?;
+                let content = params["content"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `content`"))?;
+                fs::write(path, content).await.map_err(anyhow::Error::from)?;
                 Ok(Value::String("File written".to_string()))
             }
-            _ => Err("Unknown action".to_string()),
+            _ => Err(AdapterError::MethodNotFound),
+        }
+    }
+
+    async fn subscribe(&self, action: &str, params: Value) -> Result<Subscription, AdapterError> {
+        match action {
+            "tail" => {
+                let path = params["path"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `path`"))?
+                    .to_string();
+
+                let (tx, rx) = tokio::sync::mpsc::channel(32);
+                let (cancel_tx, mut cancel_rx) = oneshot::channel();
+
+                tokio::spawn(async move {
+                    let mut offset: u64 = 0;
+                    let mut interval = tokio::time::interval(Duration::from_millis(500));
+                    loop {
+                        tokio::select! {
+                            _ = &mut cancel_rx => break,
+                            _ = interval.tick() => {
+                                let Ok(contents) = fs::read(&path).await else { continue };
+                                let len = contents.len() as u64;
+                                if len < offset {
+                                    // The file shrank (truncation, log rotation) — the
+                                    // old offset no longer means anything, so start
+                                    // over from the beginning instead of staying stuck
+                                    // past the end of the file forever.
+                                    offset = 0;
+                                }
+                                if len <= offset {
+                                    continue;
+                                }
+                                let chunk = String::from_utf8_lossy(&contents[offset as usize..]).into_owned();
+                                offset = contents.len() as u64;
+                                if tx.send(Value::String(chunk)).await.is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                });
+
+                Ok(Subscription {
+                    stream: Box::pin(ReceiverStream::new(rx)),
+                    cancel: cancel_tx,
+                })
+            }
+            _ => Err(AdapterError::MethodNotFound),
+        }
+    }
+
+    fn describe(&self) -> AdapterSchema {
+        AdapterSchema {
+            name: "file".to_string(),
+            actions: vec![
+                ActionSchema {
+                    action: "write".to_string(),
+                    params: json!({
+                        "type": "object",
+                        "required": ["path", "content"],
+                        "properties": {
+                            "path": { "type": "string" },
+                            "content": { "type": "string" }
+                        }
+                    }),
+                },
+                ActionSchema {
+                    action: "tail".to_string(),
+                    params: json!({
+                        "type": "object",
+                        "required": ["path"],
+                        "properties": {
+                            "path": { "type": "string" }
+                        }
+                    }),
+                },
+            ],
         }
     }
 }
@@ -0,0 +1,4 @@
+pub mod api;
+pub mod calculator;
+pub mod file;
+pub mod s3;
@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use aws_sdk_s3::config::{BehaviorVersion, Builder as S3ConfigBuilder, Credentials, Region};
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::Client;
+use base64::engine::{general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use crate::error::AdapterError;
+use crate::registry::Adapter;
+use crate::schema::{ActionSchema, AdapterSchema};
+
+/// Connection details for an S3-compatible object store. `endpoint_url` is
+/// optional so the same adapter works against real AWS S3 or a
+/// self-hosted/compatible endpoint (e.g. MinIO, R2).
+pub struct S3Config {
+    pub bucket: String,
+    pub region: String,
+    pub endpoint_url: Option<String>,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+pub struct S3Adapter {
+    client: Client,
+    bucket: String,
+}
+
+impl S3Adapter {
+    pub fn new(config: S3Config) -> Self {
+        let credentials = Credentials::new(
+            config.access_key_id,
+            config.secret_access_key,
+            None,
+            None,
+            "mcp-code",
+        );
+
+        let mut builder = S3ConfigBuilder::new()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(config.region))
+            .credentials_provider(credentials);
+
+        if let Some(endpoint_url) = config.endpoint_url {
+            builder = builder.endpoint_url(endpoint_url).force_path_style(true);
+        }
+
+        Self {
+            client: Client::from_conf(builder.build()),
+            bucket: config.bucket,
+        }
+    }
+
+    fn bucket<'a>(&'a self, params: &'a Value) -> &'a str {
+        params["bucket"].as_str().unwrap_or(&self.bucket)
+    }
+}
+
+#[async_trait]
+impl Adapter for S3Adapter {
+    async fn handle(&self, action: &str, params: Value) -> Result<Value, AdapterError> {
+        match action {
+            "get" => {
+                let key = params["key"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `key`"))?;
+                let output = self
+                    .client
+                    .get_object()
+                    .bucket(self.bucket(&params))
+                    .key(key)
+                    .send()
+                    .await
+                    .map_err(anyhow::Error::from)?;
+                let bytes = output
+                    .body
+                    .collect()
+                    .await
+                    .map_err(anyhow::Error::from)?
+                    .into_bytes();
+                Ok(json!({ "content": STANDARD.encode(bytes), "encoding": "base64" }))
+            }
+            "put" => {
+                let key = params["key"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `key`"))?;
+                let content = params["content"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `content`"))?;
+                self.client
+                    .put_object()
+                    .bucket(self.bucket(&params))
+                    .key(key)
+                    .body(ByteStream::from(content.as_bytes().to_vec()))
+                    .send()
+                    .await
+                    .map_err(anyhow::Error::from)?;
+                Ok(Value::String("Object written".to_string()))
+            }
+            "delete" => {
+                let key = params["key"]
+                    .as_str()
+                    .ok_or_else(|| AdapterError::invalid_params("missing `key`"))?;
+                self.client
+                    .delete_object()
+                    .bucket(self.bucket(&params))
+                    .key(key)
+                    .send()
+                    .await
+                    .map_err(anyhow::Error::from)?;
+                Ok(Value::String("Object deleted".to_string()))
+            }
+            "list" => {
+                let mut request = self.client.list_objects_v2().bucket(self.bucket(&params));
+                if let Some(prefix) = params["prefix"].as_str() {
+                    request = request.prefix(prefix);
+                }
+                let output = request.send().await.map_err(anyhow::Error::from)?;
+                let keys: Vec<Value> = output
+                    .contents()
+                    .iter()
+                    .filter_map(|object| object.key().map(|key| Value::String(key.to_string())))
+                    .collect();
+                Ok(Value::Array(keys))
+            }
+            _ => Err(AdapterError::MethodNotFound),
+        }
+    }
+
+    fn describe(&self) -> AdapterSchema {
+        AdapterSchema {
+            name: "s3".to_string(),
+            actions: vec![
+                ActionSchema {
+                    action: "get".to_string(),
+                    params: json!({
+                        "type": "object",
+                        "required": ["key"],
+                        "properties": {
+                            "bucket": { "type": "string" },
+                            "key": { "type": "string" }
+                        }
+                    }),
+                },
+                ActionSchema {
+                    action: "put".to_string(),
+                    params: json!({
+                        "type": "object",
+                        "required": ["key", "content"],
+                        "properties": {
+                            "bucket": { "type": "string" },
+                            "key": { "type": "string" },
+                            "content": { "type": "string" }
+                        }
+                    }),
+                },
+                ActionSchema {
+                    action: "delete".to_string(),
+                    params: json!({
+                        "type": "object",
+                        "required": ["key"],
+                        "properties": {
+                            "bucket": { "type": "string" },
+                            "key": { "type": "string" }
+                        }
+                    }),
+                },
+                ActionSchema {
+                    action: "list".to_string(),
+                    params: json!({
+                        "type": "object",
+                        "required": [],
+                        "properties": {
+                            "bucket": { "type": "string" },
+                            "prefix": { "type": "string" }
+                        }
+                    }),
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde_json::Value;
+use std::fmt;
+
+/// Error returned by `Adapter::handle`, distinct from the JSON-RPC transport
+/// error that the `Executor` ultimately sends over the wire. Adapters return
+/// this so the `Executor` can map each variant onto the correct JSON-RPC
+/// error code deterministically instead of guessing from a string.
+#[derive(Debug)]
+pub enum AdapterError {
+    MethodNotFound,
+    InvalidParams { reason: String },
+    Internal(anyhow::Error),
+    Custom {
+        code: i64,
+        message: String,
+        data: Option<Value>,
+    },
+}
+
+impl AdapterError {
+    pub fn invalid_params(reason: impl Into<String>) -> Self {
+        Self::InvalidParams {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for AdapterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MethodNotFound => write!(f, "method not found"),
+            Self::InvalidParams { reason } => write!(f, "invalid params: {reason}"),
+            Self::Internal(err) => write!(f, "internal error: {err}"),
+            Self::Custom { message, .. } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for AdapterError {}
+
+impl From<anyhow::Error> for AdapterError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::Internal(err)
+    }
+}
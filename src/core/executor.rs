@@ -1,32 +1,225 @@
-use crate::registry::Registry;
-use serde_json::Value;
-use tokio::sync::mpsc;
-use serde_json::json;
+use crate::error::AdapterError;
+use crate::registry::{Adapter, Registry};
+use crate::rpc::{self, JsonRpcRequest, JsonRpcResponse};
+use crate::schema;
+use crate::subscription::SubscriptionId;
+use futures::StreamExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+/// Built-in introspection method that lists every registered adapter's
+/// schema, so clients can discover and validate capabilities up front
+/// instead of hitting `-32601 Method not found` at call time.
+const TOOLS_LIST_METHOD: &str = "tools/list";
+const SUBSCRIBE_METHOD: &str = "subscribe";
+const UNSUBSCRIBE_METHOD: &str = "unsubscribe";
 
 pub struct Executor {
     registry: Registry,
     sender: mpsc::Sender<Value>,
+    subscriptions: Arc<Mutex<HashMap<SubscriptionId, oneshot::Sender<()>>>>,
+    next_subscription_id: AtomicU64,
 }
 
 impl Executor {
     pub fn new(registry: Registry, sender: mpsc::Sender<Value>) -> Self {
-        Self { registry, sender }
+        Self {
+            registry,
+            sender,
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            next_subscription_id: AtomicU64::new(1),
+        }
     }
 
+    /// Executes a single JSON-RPC request object or a batch array. Batches are
+    /// dispatched element by element and replied to as a single array, in the
+    /// same order they were received. Notifications (requests with no `id`)
+    /// never produce a response on the channel — nor does an empty batch:
+    /// the spec requires an empty `[]` to get back a single `-32600 Invalid
+    /// Request` object rather than an array, which doesn't fit this
+    /// function's per-batch-element response channel, so `server` handles
+    /// that case itself before ever calling `execute`.
     pub async fn execute(&self, request: Value) {
-        let adapter_name = request["adapter"].as_str().unwrap_or("");
-        let action = request["action"].as_str().unwrap_or("");
-        let params = request["params"].clone();
+        let response = match request {
+            Value::Array(batch) => self.execute_batch(batch).await,
+            single => self
+                .dispatch(single)
+                .await
+                .map(|response| serde_json::to_value(response).unwrap()),
+        };
 
-        if let Some(adapter) = self.registry.get(adapter_name).await {
-            let result = adapter.handle(action, params).await;
+        if let Some(response) = response {
+            let _ = self.sender.send(response).await;
+        }
+    }
 
-            let response = match result {
-                Ok(data) => json!({ "status": "success", "data": data }),
-                Err(err) => json!({ "status": "error", "message": err }),
-            };
+    async fn execute_batch(&self, batch: Vec<Value>) -> Option<Value> {
+        let mut responses = Vec::with_capacity(batch.len());
+        for item in batch {
+            if let Some(response) = self.dispatch(item).await {
+                responses.push(serde_json::to_value(response).unwrap());
+            }
+        }
 
-            let _ = self.sender.send(response).await;
+        if responses.is_empty() {
+            None
+        } else {
+            Some(Value::Array(responses))
+        }
+    }
+
+    async fn dispatch(&self, request: Value) -> Option<JsonRpcResponse> {
+        // Grab `id` from the raw value up front: if parsing fails below, the
+        // caller still needs it echoed back to correlate the error response,
+        // and `JsonRpcRequest` itself no longer exists once parsing has failed.
+        let raw_id = request.get("id").cloned().unwrap_or(Value::Null);
+        let request: JsonRpcRequest = match serde_json::from_value(request) {
+            Ok(request) => request,
+            Err(err) => {
+                return Some(JsonRpcResponse::error(
+                    raw_id,
+                    rpc::INVALID_REQUEST,
+                    format!("Invalid Request: {err}"),
+                    None,
+                ))
+            }
+        };
+
+        let is_notification = request.id.is_none();
+        let id = request.id.unwrap_or(Value::Null);
+
+        let response = if request.method == TOOLS_LIST_METHOD {
+            let tools = self.registry.describe_all();
+            JsonRpcResponse::success(id, serde_json::to_value(tools).unwrap())
+        } else if request.method == SUBSCRIBE_METHOD {
+            self.handle_subscribe(id, request.params).await
+        } else if request.method == UNSUBSCRIBE_METHOD {
+            self.handle_unsubscribe(id, request.params).await
+        } else {
+            match request.method.rsplit_once('.') {
+                Some((adapter_name, action)) => match self.registry.get(adapter_name).await {
+                    Some(adapter) => match Self::validate(adapter, action, &request.params) {
+                        Ok(()) => match adapter.handle(action, request.params).await {
+                            Ok(result) => JsonRpcResponse::success(id, result),
+                            Err(err) => Self::error_response(id, err),
+                        },
+                        Err(reason) => JsonRpcResponse::error(id, rpc::INVALID_PARAMS, reason, None),
+                    },
+                    None => JsonRpcResponse::error(
+                        id,
+                        rpc::METHOD_NOT_FOUND,
+                        format!("Unknown adapter '{adapter_name}'"),
+                        None,
+                    ),
+                },
+                None => JsonRpcResponse::error(
+                    id,
+                    rpc::METHOD_NOT_FOUND,
+                    format!("method '{}' must be '<adapter>.<action>'", request.method),
+                    None,
+                ),
+            }
+        };
+
+        if is_notification {
+            None
+        } else {
+            Some(response)
+        }
+    }
+
+    /// Opens a subscription on `params.method` (itself an `<adapter>.<action>`
+    /// method) and spawns a task that forwards every item the adapter's
+    /// stream yields as a `subscription` notification on the shared sender.
+    ///
+    /// Notifications carry no `id`, so nothing in `POST /rpc`'s
+    /// request/response cycle can deliver them back to a caller — the HTTP
+    /// layer (`server::run_dispatcher`) forwards unmatched messages to a
+    /// `GET /rpc/notifications` Server-Sent-Events stream instead. A caller
+    /// that never connects there will have its subscription run, but never
+    /// see any of the values it produces.
+    async fn handle_subscribe(&self, id: Value, params: Value) -> JsonRpcResponse {
+        let method = match params["method"].as_str() {
+            Some(method) => method,
+            None => return JsonRpcResponse::error(id, rpc::INVALID_PARAMS, "missing `method`", None),
+        };
+
+        let Some((adapter_name, action)) = method.rsplit_once('.') else {
+            return JsonRpcResponse::error(id, rpc::INVALID_PARAMS, "`method` must be '<adapter>.<action>'", None);
+        };
+
+        let Some(adapter) = self.registry.get(adapter_name).await else {
+            return JsonRpcResponse::error(id, rpc::METHOD_NOT_FOUND, format!("Unknown adapter '{adapter_name}'"), None);
+        };
+
+        let subscription = match adapter.subscribe(action, params["params"].clone()).await {
+            Ok(subscription) => subscription,
+            Err(err) => return Self::error_response(id, err),
+        };
+
+        let subscription_id = self.next_subscription_id.fetch_add(1, Ordering::SeqCst);
+        self.subscriptions.lock().await.insert(subscription_id, subscription.cancel);
+
+        let sender = self.sender.clone();
+        let subscriptions = self.subscriptions.clone();
+        let mut stream = subscription.stream;
+        tokio::spawn(async move {
+            while let Some(item) = stream.next().await {
+                let notification = json!({
+                    "jsonrpc": "2.0",
+                    "method": "subscription",
+                    "params": { "subscription": subscription_id, "result": item },
+                });
+                if sender.send(notification).await.is_err() {
+                    break;
+                }
+            }
+            // The stream can end on its own (not just via `unsubscribe`) —
+            // drop the now-stale cancel handle so it doesn't leak forever.
+            subscriptions.lock().await.remove(&subscription_id);
+        });
+
+        JsonRpcResponse::success(id, json!({ "subscription": subscription_id }))
+    }
+
+    async fn handle_unsubscribe(&self, id: Value, params: Value) -> JsonRpcResponse {
+        let Some(subscription_id) = params["subscription"].as_u64() else {
+            return JsonRpcResponse::error(id, rpc::INVALID_PARAMS, "missing `subscription`", None);
+        };
+
+        match self.subscriptions.lock().await.remove(&subscription_id) {
+            Some(cancel) => {
+                let _ = cancel.send(());
+                JsonRpcResponse::success(id, json!({ "unsubscribed": true }))
+            }
+            None => JsonRpcResponse::error(id, rpc::INVALID_PARAMS, "unknown subscription", None),
+        }
+    }
+
+    fn validate(adapter: &dyn Adapter, action: &str, params: &Value) -> Result<(), String> {
+        match adapter.describe().actions.into_iter().find(|a| a.action == action) {
+            Some(action_schema) => schema::validate_params(&action_schema.params, params),
+            None => Ok(()),
+        }
+    }
+
+    fn error_response(id: Value, err: AdapterError) -> JsonRpcResponse {
+        match err {
+            AdapterError::MethodNotFound => {
+                JsonRpcResponse::error(id, rpc::METHOD_NOT_FOUND, "method not found", None)
+            }
+            AdapterError::InvalidParams { reason } => {
+                JsonRpcResponse::error(id, rpc::INVALID_PARAMS, reason, None)
+            }
+            AdapterError::Internal(err) => {
+                JsonRpcResponse::error(id, rpc::INTERNAL_ERROR, err.to_string(), None)
+            }
+            AdapterError::Custom { code, message, data } => {
+                JsonRpcResponse::error(id, code, message, data)
+            }
         }
     }
 }
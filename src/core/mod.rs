@@ -0,0 +1,7 @@
+pub mod adapters;
+pub mod error;
+pub mod executor;
+pub mod registry;
+pub mod rpc;
+pub mod schema;
+pub mod subscription;
@@ -1,10 +1,24 @@
+use crate::error::AdapterError;
+use crate::schema::AdapterSchema;
+use crate::subscription::Subscription;
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
 
 #[async_trait]
 pub trait Adapter: Send + Sync {
-    async fn handle(&self, action: &str, params: Value) -> Result<Value, String>;
+    async fn handle(&self, action: &str, params: Value) -> Result<Value, AdapterError>;
+
+    /// Describes the adapter's name, supported actions, and each action's
+    /// expected `params`, for the `tools/list` introspection method.
+    fn describe(&self) -> AdapterSchema;
+
+    /// Opens a long-running subscription for `action`, e.g. tailing a file
+    /// or polling an API, yielding a stream of values instead of a single
+    /// result. Adapters that have no such actions can leave this unimplemented.
+    async fn subscribe(&self, _action: &str, _params: Value) -> Result<Subscription, AdapterError> {
+        Err(AdapterError::MethodNotFound)
+    }
 }
 
 pub struct Registry {
@@ -25,4 +39,8 @@ impl Registry {
     pub async fn get(&self, name: &str) -> Option<&Box<dyn Adapter>> {
         self.adapters.get(name)
     }
+
+    pub fn describe_all(&self) -> Vec<AdapterSchema> {
+        self.adapters.values().map(|adapter| adapter.describe()).collect()
+    }
 }
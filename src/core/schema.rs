@@ -0,0 +1,57 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// JSON-Schema-style description of a single action's `params`, as returned
+/// by `Adapter::describe` and surfaced through the `tools/list` method.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionSchema {
+    pub action: String,
+    pub params: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdapterSchema {
+    pub name: String,
+    pub actions: Vec<ActionSchema>,
+}
+
+/// Checks `params` against a JSON-Schema-style object (`required` + typed
+/// `properties`). Only the subset needed to catch the common mistakes
+/// (missing field, wrong type) is implemented.
+pub fn validate_params(schema: &Value, params: &Value) -> Result<(), String> {
+    if let Some(required) = schema["required"].as_array() {
+        for field in required {
+            let name = field.as_str().unwrap_or_default();
+            if params.get(name).is_none() {
+                return Err(format!("missing required field `{name}`"));
+            }
+        }
+    }
+
+    if let Some(properties) = schema["properties"].as_object() {
+        for (name, property) in properties {
+            let Some(value) = params.get(name) else {
+                continue;
+            };
+            if let Some(expected_type) = property["type"].as_str() {
+                if !type_matches(expected_type, value) {
+                    return Err(format!("field `{name}` must be of type `{expected_type}`"));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn type_matches(expected_type: &str, value: &Value) -> bool {
+    match expected_type {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        _ => true,
+    }
+}
@@ -0,0 +1,14 @@
+use futures::Stream;
+use serde_json::Value;
+use std::pin::Pin;
+use tokio::sync::oneshot;
+
+pub type SubscriptionId = u64;
+
+/// What `Adapter::subscribe` hands back to the `Executor`: a stream of
+/// values to forward as subscription notifications, and a handle the
+/// `Executor` fires on `unsubscribe` to tell the adapter to stop producing.
+pub struct Subscription {
+    pub stream: Pin<Box<dyn Stream<Item = Value> + Send>>,
+    pub cancel: oneshot::Sender<()>,
+}
@@ -1,39 +1,46 @@
 mod core;
-use core::{adapters, executor, registry};
+mod server;
 
-use axum::{routing::post, Json, Router};
-use crate::adapters::{api::ApiAdapter, calculator::CalculatorAdapter, file::FileAdapter};
+use core::{adapters, error, executor, registry, rpc, schema, subscription};
+
+use crate::adapters::{api::ApiAdapter, calculator::CalculatorAdapter, file::FileAdapter, s3::{S3Adapter, S3Config}};
 use crate::executor::Executor;
 use crate::registry::Registry;
-use tokio::sync::mpsc;
+use crate::server::{router, run_dispatcher, AppState};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc, Mutex};
 
 #[tokio::main]
 async fn main() {
-    let (tx, mut rx) = mpsc::channel(32);
+    let (tx, rx) = mpsc::channel(32);
 
     // Registryにアダプターを登録
     let mut registry = Registry::new();
     registry.register("api", Box::new(ApiAdapter::new()));
     registry.register("file", Box::new(FileAdapter::new()));
     registry.register("calc", Box::new(CalculatorAdapter::new()));
+    registry.register(
+        "s3",
+        Box::new(S3Adapter::new(S3Config {
+            bucket: std::env::var("S3_BUCKET").unwrap_or_default(),
+            region: std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            endpoint_url: std::env::var("S3_ENDPOINT_URL").ok(),
+            access_key_id: std::env::var("S3_ACCESS_KEY_ID").unwrap_or_default(),
+            secret_access_key: std::env::var("S3_SECRET_ACCESS_KEY").unwrap_or_default(),
+        })),
+    );
 
     // Executorを起動
-    let executor = Executor::new(registry, tx.clone());
-
-    // シンプルなリクエスト例
-    let request = serde_json::json!({
-        "adapter": "calc",
-        "action": "add",
-        "params": {
-            "a": 5,
-            "b": 10
-        }
-    });
+    let executor = Arc::new(Executor::new(registry, tx));
+    let pending = Arc::new(Mutex::new(HashMap::new()));
+    let (notifications, _) = broadcast::channel(128);
 
-    executor.execute(request).await;
+    // Executorからの応答を、待機中のHTTPリクエストへ振り分ける
+    tokio::spawn(run_dispatcher(rx, pending.clone(), notifications.clone()));
 
-    // 結果の受け取り
-    while let Some(result) = rx.recv().await {
-        println!("Result: {:?}", result);
-    }
+    let app = router(AppState::new(executor, pending, notifications));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await.unwrap();
+    println!("listening on {}", listener.local_addr().unwrap());
+    axum::serve(listener, app).await.unwrap();
 }
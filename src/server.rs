@@ -0,0 +1,236 @@
+use crate::executor::Executor;
+use crate::rpc::{self, JsonRpcRequest, JsonRpcResponse};
+use axum::extract::State;
+use axum::response::sse::{Event, Sse};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use futures::Stream;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt as _;
+
+/// Queued rather than single-slot: an `id` that can't be rewritten to a
+/// unique value (see `rewrite_id` below) still has to share a key with
+/// whichever other in-flight request also couldn't be rewritten, and a plain
+/// `HashMap<String, oneshot::Sender<Value>>` would let a second insert
+/// silently drop the first waiter's sender.
+pub type Pending = Arc<Mutex<HashMap<String, VecDeque<oneshot::Sender<Value>>>>>;
+
+#[derive(Clone)]
+pub struct AppState {
+    executor: Arc<Executor>,
+    pending: Pending,
+    notifications: broadcast::Sender<Value>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl AppState {
+    pub fn new(executor: Arc<Executor>, pending: Pending, notifications: broadcast::Sender<Value>) -> Self {
+        Self {
+            executor,
+            pending,
+            notifications,
+            next_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/rpc", post(handle_rpc))
+        .route("/rpc/notifications", get(handle_notifications))
+        .with_state(state)
+}
+
+/// Forwards every response the `Executor` produces to the oneshot channel of
+/// whichever HTTP request is waiting on the matching JSON-RPC id, so that
+/// concurrent requests sharing one `Executor` never receive each other's
+/// results. `subscription` notifications carry no `id` at all — they're
+/// recognized by shape and broadcast unconditionally, rather than falling
+/// into the same `id`-keyed lookup a genuine `"id": null` response uses, so
+/// the two can never steal each other's slot in `pending`.
+pub async fn run_dispatcher(mut rx: mpsc::Receiver<Value>, pending: Pending, notifications: broadcast::Sender<Value>) {
+    while let Some(response) = rx.recv().await {
+        if is_subscription_notification(&response) {
+            // No subscribers is the common case when nobody is streaming
+            // notifications; that's fine, there's nobody to deliver to.
+            let _ = notifications.send(response);
+            continue;
+        }
+
+        let key = correlation_key(&response);
+        let sender = {
+            let mut pending = pending.lock().await;
+            match pending.get_mut(&key) {
+                Some(queue) => {
+                    let sender = queue.pop_front();
+                    if queue.is_empty() {
+                        pending.remove(&key);
+                    }
+                    sender
+                }
+                None => None,
+            }
+        };
+
+        match sender {
+            Some(sender) => {
+                let _ = sender.send(response);
+            }
+            None => {
+                let _ = notifications.send(response);
+            }
+        }
+    }
+}
+
+/// `subscription` notifications are the only messages `Executor` emits with
+/// a `method` field instead of an `id` — every `JsonRpcResponse` always
+/// carries an `id`, even when that `id` is `null`.
+fn is_subscription_notification(message: &Value) -> bool {
+    message["method"] == "subscription"
+}
+
+/// Streams `subscription` notifications as Server-Sent Events. `POST /rpc`
+/// can only ever carry one response per request, so it has no way to push
+/// the id-less notifications a subscription produces — a client that wants
+/// them has to hold this connection open instead.
+async fn handle_notifications(State(state): State<AppState>) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(state.notifications.subscribe())
+        .filter_map(|item| item.ok())
+        .map(|value| Ok(Event::default().json_data(value).unwrap_or_else(|_| Event::default())));
+    Sse::new(stream)
+}
+
+async fn handle_rpc(State(state): State<AppState>, Json(request): Json<Value>) -> Json<Value> {
+    match request {
+        Value::Array(items) => handle_batch(&state, items).await,
+        single => handle_single(&state, single).await,
+    }
+}
+
+/// Handles a lone JSON-RPC request object.
+///
+/// The client-supplied `id` is whatever the client chose — two concurrent
+/// callers both starting their own counter at `1` is normal, not malicious —
+/// so it can't be used to key `pending` directly, or the second caller's
+/// insert would silently replace the first's sender and hand the first
+/// caller someone else's response. Instead a server-generated id is swapped
+/// in for the duration of the call and the original is restored before the
+/// response goes back out.
+async fn handle_single(state: &AppState, mut request: Value) -> Json<Value> {
+    let Some(original_id) = response_id_for_request(&request) else {
+        // A pure notification has no response to wait for.
+        state.executor.execute(request).await;
+        return Json(Value::Null);
+    };
+
+    let synthetic_id = rewrite_id(state, &mut request);
+    let rx = register_pending(state, key_for_id(&synthetic_id)).await;
+    state.executor.execute(request).await;
+
+    let mut response = rx.await.unwrap_or_else(|_| internal_error_response());
+    response["id"] = original_id;
+    Json(response)
+}
+
+/// Handles a JSON-RPC batch the same way `handle_single` handles one
+/// request, rewriting each element's `id` individually and restoring the
+/// originals position-for-position in the response array, which `Executor`
+/// guarantees preserves the elements' relative order.
+async fn handle_batch(state: &AppState, mut items: Vec<Value>) -> Json<Value> {
+    if items.is_empty() {
+        // The spec requires `[]` to produce a single -32600 Invalid Request
+        // object, not an array — that doesn't fit `Executor`'s per-element
+        // response channel, nor does it have an id of its own to correlate,
+        // so it's answered directly rather than round-tripped through it.
+        let error = JsonRpcResponse::error(Value::Null, rpc::INVALID_REQUEST, "Invalid Request", None);
+        return Json(serde_json::to_value(error).unwrap_or(Value::Null));
+    }
+
+    let mut original_ids = Vec::with_capacity(items.len());
+    let mut synthetic_ids = Vec::with_capacity(items.len());
+    for item in items.iter_mut() {
+        let Some(original_id) = response_id_for_request(item) else {
+            continue;
+        };
+        synthetic_ids.push(rewrite_id(state, item));
+        original_ids.push(original_id);
+    }
+
+    if original_ids.is_empty() {
+        // Every element was a notification.
+        state.executor.execute(Value::Array(items)).await;
+        return Json(Value::Null);
+    }
+
+    let key = serde_json::to_string(&synthetic_ids).unwrap_or_default();
+    let rx = register_pending(state, key).await;
+    state.executor.execute(Value::Array(items)).await;
+
+    let mut response = rx.await.unwrap_or_else(|_| internal_error_response());
+    if let Value::Array(responses) = &mut response {
+        for (response_item, original_id) in responses.iter_mut().zip(original_ids) {
+            response_item["id"] = original_id;
+        }
+    }
+    Json(response)
+}
+
+/// Replaces `request`'s `id` with a freshly allocated one and returns that
+/// id, for use as (part of) the key its response will be correlated under.
+/// `request` has to be a JSON object for this to mean anything — for the
+/// rare body that isn't even an object, the `id` can't be rewritten (there's
+/// nowhere to put it), so this returns `Value::Null`, the id `Executor` will
+/// actually respond with.
+fn rewrite_id(state: &AppState, request: &mut Value) -> Value {
+    if request.is_object() {
+        let synthetic_id = state.next_id.fetch_add(1, Ordering::SeqCst);
+        request["id"] = json!(synthetic_id);
+        json!(synthetic_id)
+    } else {
+        Value::Null
+    }
+}
+
+fn key_for_id(id: &Value) -> String {
+    serde_json::to_string(id).unwrap_or_default()
+}
+
+async fn register_pending(state: &AppState, key: String) -> oneshot::Receiver<Value> {
+    let (tx, rx) = oneshot::channel();
+    state.pending.lock().await.entry(key).or_default().push_back(tx);
+    rx
+}
+
+fn internal_error_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "error": { "code": -32603, "message": "internal error: response was never dispatched" },
+        "id": Value::Null,
+    })
+}
+
+fn correlation_key(response: &Value) -> String {
+    match response {
+        Value::Array(responses) => {
+            let ids: Vec<&Value> = responses.iter().map(|item| &item["id"]).collect();
+            serde_json::to_string(&ids).unwrap_or_default()
+        }
+        single => serde_json::to_string(&single["id"]).unwrap_or_default(),
+    }
+}
+
+/// The `id` the response to `item` will carry, or `None` if `item` is a
+/// well-formed notification (parses fine, `id` absent) that gets no response.
+fn response_id_for_request(item: &Value) -> Option<Value> {
+    match serde_json::from_value::<JsonRpcRequest>(item.clone()) {
+        Ok(request) => request.id,
+        Err(_) => Some(item.get("id").cloned().unwrap_or(Value::Null)),
+    }
+}